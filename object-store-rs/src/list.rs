@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
-use futures::stream::BoxStream;
+use futures::future::{BoxFuture, FutureExt};
+use futures::stream::{self, BoxStream};
 use futures::StreamExt;
 use indexmap::IndexMap;
 use object_store::path::Path;
@@ -9,6 +10,7 @@ use pyo3::prelude::*;
 use pyo3_object_store::error::{PyObjectStoreError, PyObjectStoreResult};
 use pyo3_object_store::PyObjectStore;
 
+use crate::list_stream::PyListStream;
 use crate::runtime::get_runtime;
 
 pub(crate) struct PyObjectMeta(ObjectMeta);
@@ -33,14 +35,21 @@ impl IntoPy<PyObject> for PyObjectMeta {
     }
 }
 
-pub(crate) struct PyListResult(ListResult);
+pub(crate) struct PyListResult {
+    inner: ListResult,
+    /// Populated only when `list_with_delimiter` was called with `recursive=True`: one
+    /// nested [`PyListResult`] per entry of `inner.common_prefixes`, keyed by that
+    /// prefix as a `dict`, so callers can render a full directory tree from a single
+    /// call.
+    children: Option<IndexMap<String, PyListResult>>,
+}
 
 impl IntoPy<PyObject> for PyListResult {
     fn into_py(self, py: Python<'_>) -> PyObject {
-        let mut dict = IndexMap::with_capacity(2);
+        let mut dict = IndexMap::with_capacity(3);
         dict.insert(
             "common_prefixes",
-            self.0
+            self.inner
                 .common_prefixes
                 .into_iter()
                 .map(String::from)
@@ -49,17 +58,37 @@ impl IntoPy<PyObject> for PyListResult {
         );
         dict.insert(
             "objects",
-            self.0
+            self.inner
                 .objects
                 .into_iter()
                 .map(PyObjectMeta)
                 .collect::<Vec<_>>()
                 .into_py(py),
         );
+        if let Some(children) = self.children {
+            dict.insert("children", children.into_py(py));
+        }
         dict.into_py(py)
     }
 }
 
+/// Drop every common prefix and object lexically at or below `offset`, mirroring the
+/// semantics of `ObjectStore::list_with_offset`.
+fn apply_offset(result: ListResult, offset: &str) -> ListResult {
+    ListResult {
+        common_prefixes: result
+            .common_prefixes
+            .into_iter()
+            .filter(|p| p.as_ref() > offset)
+            .collect(),
+        objects: result
+            .objects
+            .into_iter()
+            .filter(|o| o.location.as_ref() > offset)
+            .collect(),
+    }
+}
+
 #[pyfunction]
 #[pyo3(signature = (store, prefix = None, *, offset = None, max_items = 2000))]
 pub(crate) fn list(
@@ -105,7 +134,24 @@ pub(crate) fn list_async(
     })
 }
 
-async fn materialize_list_stream(
+/// Construct a [`PyListStream`] that lazily pages through a prefix instead of
+/// materializing it all at once. The returned object supports both the sync and
+/// async iterator protocols, yielding up to `chunk_size` [`PyObjectMeta`] per call.
+#[pyfunction]
+#[pyo3(signature = (store, prefix = None, *, offset = None, chunk_size = 50))]
+pub(crate) fn list_stream(
+    store: PyObjectStore,
+    prefix: Option<String>,
+    offset: Option<String>,
+    chunk_size: usize,
+) -> PyListStream {
+    let store = store.into_inner();
+    let prefix = prefix.map(|s| s.into());
+    let offset = offset.map(|s| s.into());
+    PyListStream::new(store, prefix, offset, chunk_size)
+}
+
+pub(crate) async fn materialize_list_stream(
     mut stream: BoxStream<'_, object_store::Result<ObjectMeta>>,
     max_items: Option<usize>,
 ) -> PyObjectStoreResult<Vec<PyObjectMeta>> {
@@ -122,42 +168,145 @@ async fn materialize_list_stream(
     Ok(result)
 }
 
+/// List common prefixes and objects one level below `prefix`.
+///
+/// `offset` skips common prefixes and objects that sort lexically at or below it,
+/// letting a caller page through a single very wide "directory" instead of re-listing
+/// it from the top each time. When `recursive=True`, every returned common prefix is
+/// descended into concurrently and accumulated into `PyListResult.children`, so a
+/// whole hierarchy can be walked in one call instead of issuing N manual delimiter
+/// listings; `offset` only applies to the top-level listing in that mode.
 #[pyfunction]
-#[pyo3(signature = (store, prefix = None))]
+#[pyo3(signature = (store, prefix = None, *, offset = None, recursive = false))]
 pub(crate) fn list_with_delimiter(
     py: Python,
     store: PyObjectStore,
     prefix: Option<String>,
+    offset: Option<String>,
+    recursive: bool,
 ) -> PyObjectStoreResult<PyListResult> {
     let runtime = get_runtime(py)?;
     py.allow_threads(|| {
         let out = runtime.block_on(list_with_delimiter_materialize(
             store.into_inner(),
-            prefix.map(|s| s.into()).as_ref(),
+            prefix.map(|s| s.into()),
+            offset,
+            recursive,
         ))?;
         Ok::<_, PyObjectStoreError>(out)
     })
 }
 
 #[pyfunction]
-#[pyo3(signature = (store, prefix = None))]
+#[pyo3(signature = (store, prefix = None, *, offset = None, recursive = false))]
 pub(crate) fn list_with_delimiter_async(
     py: Python,
     store: PyObjectStore,
     prefix: Option<String>,
+    offset: Option<String>,
+    recursive: bool,
 ) -> PyResult<Bound<PyAny>> {
     pyo3_async_runtimes::tokio::future_into_py(py, async move {
-        let out =
-            list_with_delimiter_materialize(store.into_inner(), prefix.map(|s| s.into()).as_ref())
-                .await?;
+        let out = list_with_delimiter_materialize(
+            store.into_inner(),
+            prefix.map(|s| s.into()),
+            offset,
+            recursive,
+        )
+        .await?;
         Ok(out)
     })
 }
 
-async fn list_with_delimiter_materialize(
+/// Caps the number of child `list_with_delimiter` calls that one directory's listing
+/// runs concurrently in a recursive listing, so a single very wide "directory" can't
+/// fan out to unbounded concurrent requests. Note this bounds each directory's fan-out
+/// independently, not a level or the whole tree: sibling directories and deeper levels
+/// each run their own bounded batch, so more calls than this can be in flight at once.
+const RECURSIVE_LIST_CONCURRENCY: usize = 8;
+
+pub(crate) fn list_with_delimiter_materialize(
     store: Arc<dyn ObjectStore>,
-    prefix: Option<&Path>,
-) -> PyObjectStoreResult<PyListResult> {
-    let list_result = store.list_with_delimiter(prefix).await?;
-    Ok(PyListResult(list_result))
+    prefix: Option<Path>,
+    offset: Option<String>,
+    recursive: bool,
+) -> BoxFuture<'static, PyObjectStoreResult<PyListResult>> {
+    async move {
+        let mut list_result = store.list_with_delimiter(prefix.as_ref()).await?;
+        if let Some(offset) = &offset {
+            list_result = apply_offset(list_result, offset);
+        }
+
+        let children = if recursive {
+            let scans = list_result.common_prefixes.clone().into_iter().map(|child_prefix| {
+                let store = store.clone();
+                async move {
+                    let label = child_prefix.to_string();
+                    let result =
+                        list_with_delimiter_materialize(store, Some(child_prefix), None, true)
+                            .await?;
+                    Ok::<_, PyObjectStoreError>((label, result))
+                }
+            });
+
+            let mut scans =
+                stream::iter(scans).buffer_unordered(RECURSIVE_LIST_CONCURRENCY);
+            let mut out = IndexMap::with_capacity(list_result.common_prefixes.len());
+            while let Some(entry) = scans.next().await {
+                let (label, result) = entry?;
+                out.insert(label, result);
+            }
+            Some(out)
+        } else {
+            None
+        };
+
+        Ok(PyListResult {
+            inner: list_result,
+            children,
+        })
+    }
+    .boxed()
+}
+
+#[cfg(test)]
+mod tests {
+    use object_store::{ListResult, ObjectMeta};
+
+    use super::apply_offset;
+
+    fn meta(path: &str) -> ObjectMeta {
+        ObjectMeta {
+            location: path.into(),
+            last_modified: chrono::DateTime::from_timestamp(0, 0).unwrap(),
+            size: 0,
+            e_tag: None,
+            version: None,
+        }
+    }
+
+    #[test]
+    fn apply_offset_is_exclusive_at_the_boundary() {
+        let result = ListResult {
+            common_prefixes: vec!["a".into(), "b".into(), "c".into()],
+            objects: vec![meta("a/1"), meta("b/1"), meta("c/1")],
+        };
+        let filtered = apply_offset(result, "b");
+        assert_eq!(filtered.common_prefixes, vec![object_store::path::Path::from("c")]);
+        assert_eq!(
+            filtered.objects.iter().map(|o| o.location.as_ref()).collect::<Vec<_>>(),
+            vec!["c/1"]
+        );
+    }
+
+    #[test]
+    fn apply_offset_keeps_everything_above_an_empty_offset() {
+        let result = ListResult {
+            common_prefixes: vec!["a".into()],
+            objects: vec![meta("a/1")],
+        };
+        let filtered = apply_offset(result, "");
+        assert_eq!(filtered.common_prefixes.len(), 1);
+        assert_eq!(filtered.objects.len(), 1);
+    }
 }
@@ -0,0 +1,226 @@
+use std::sync::Arc;
+
+use arrow_array::builder::{StringBuilder, TimestampMicrosecondBuilder, UInt64Builder};
+use arrow_array::{RecordBatch, RecordBatchReader};
+use arrow_schema::{ArrowError, DataType, Field, Schema, SchemaRef, TimeUnit};
+use futures::stream::BoxStream;
+use futures::StreamExt;
+use object_store::path::Path;
+use object_store::{ObjectMeta, ObjectStore};
+use pyo3::prelude::*;
+use pyo3_arrow::{PyRecordBatch, PyRecordBatchReader};
+use pyo3_object_store::error::{PyObjectStoreError, PyObjectStoreResult};
+use pyo3_object_store::PyObjectStore;
+
+use crate::runtime::get_runtime;
+
+/// The Arrow schema shared by [`list_to_arrow`] and [`list_to_arrow_stream`]: one row
+/// per [`ObjectMeta`], matching the field names used by the dict-based `list` output.
+fn object_meta_schema() -> SchemaRef {
+    Arc::new(Schema::new(vec![
+        Field::new("path", DataType::Utf8, false),
+        Field::new(
+            "last_modified",
+            DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into())),
+            false,
+        ),
+        Field::new("size", DataType::UInt64, false),
+        Field::new("e_tag", DataType::Utf8, true),
+        Field::new("version", DataType::Utf8, true),
+    ]))
+}
+
+/// Columnar builders for [`ObjectMeta`], avoiding a per-object Python allocation.
+struct ObjectMetaBuilder {
+    path: StringBuilder,
+    last_modified: TimestampMicrosecondBuilder,
+    size: UInt64Builder,
+    e_tag: StringBuilder,
+    version: StringBuilder,
+}
+
+impl ObjectMetaBuilder {
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            path: StringBuilder::with_capacity(capacity, capacity * 32),
+            last_modified: TimestampMicrosecondBuilder::with_capacity(capacity),
+            size: UInt64Builder::with_capacity(capacity),
+            e_tag: StringBuilder::with_capacity(capacity, capacity * 16),
+            version: StringBuilder::with_capacity(capacity, capacity * 16),
+        }
+    }
+
+    fn append(&mut self, meta: ObjectMeta) {
+        self.path.append_value(meta.location.as_ref());
+        self.last_modified
+            .append_value(meta.last_modified.timestamp_micros());
+        self.size.append_value(meta.size);
+        self.e_tag.append_option(meta.e_tag);
+        self.version.append_option(meta.version);
+    }
+
+    fn is_empty(&self) -> bool {
+        self.path.is_empty()
+    }
+
+    fn finish(mut self) -> RecordBatch {
+        RecordBatch::try_new(
+            object_meta_schema(),
+            vec![
+                Arc::new(self.path.finish()),
+                Arc::new(
+                    self.last_modified
+                        .finish()
+                        .with_timezone(Arc::<str>::from("UTC")),
+                ),
+                Arc::new(self.size.finish()),
+                Arc::new(self.e_tag.finish()),
+                Arc::new(self.version.finish()),
+            ],
+        )
+        .expect("ObjectMetaBuilder arrays always match the declared schema")
+    }
+}
+
+async fn collect_to_record_batch(
+    mut stream: BoxStream<'_, object_store::Result<ObjectMeta>>,
+    max_items: Option<usize>,
+) -> PyObjectStoreResult<RecordBatch> {
+    let mut builder = ObjectMetaBuilder::with_capacity(max_items.unwrap_or(1024));
+    let mut count = 0;
+    while let Some(object) = stream.next().await {
+        builder.append(object?);
+        count += 1;
+        if let Some(max_items) = max_items {
+            if count >= max_items {
+                break;
+            }
+        }
+    }
+    Ok(builder.finish())
+}
+
+/// List a prefix directly into a single Arrow [`RecordBatch`], handed to Python via the
+/// Arrow PyCapsule interface (`__arrow_c_array__`) so pyarrow/polars can consume it
+/// without building a Python object per listed key.
+#[pyfunction]
+#[pyo3(signature = (store, prefix = None, *, offset = None, max_items = None))]
+pub(crate) fn list_to_arrow(
+    py: Python,
+    store: PyObjectStore,
+    prefix: Option<String>,
+    offset: Option<String>,
+    max_items: Option<usize>,
+) -> PyObjectStoreResult<PyRecordBatch> {
+    let store = store.into_inner();
+    let prefix = prefix.map(|s| s.into());
+    let runtime = get_runtime(py)?;
+    py.allow_threads(|| {
+        let stream = if let Some(offset) = offset {
+            store.list_with_offset(prefix.as_ref(), &offset.into())
+        } else {
+            store.list(prefix.as_ref())
+        };
+        let batch = runtime.block_on(collect_to_record_batch(stream, max_items))?;
+        Ok::<_, PyObjectStoreError>(PyRecordBatch::new(batch))
+    })
+}
+
+/// Like [`list_to_arrow`], but returns a [`PyRecordBatchReader`] that yields one
+/// [`RecordBatch`] of up to `chunk_size` objects at a time as it's consumed, via the
+/// Arrow PyCapsule stream interface (`__arrow_c_stream__`). This is the arrow-shaped
+/// counterpart to [`crate::list::list_stream`]: both page through the same
+/// `BoxStream<ObjectMeta>` without ever materializing the full listing at once.
+#[pyfunction]
+#[pyo3(signature = (store, prefix = None, *, offset = None, chunk_size = 2000))]
+pub(crate) fn list_to_arrow_stream(
+    py: Python,
+    store: PyObjectStore,
+    prefix: Option<String>,
+    offset: Option<String>,
+    chunk_size: usize,
+) -> PyObjectStoreResult<PyRecordBatchReader> {
+    let runtime = get_runtime(py)?;
+    let store = store.into_inner();
+    let prefix = prefix.map(|s| s.into());
+    let offset = offset.map(|s| s.into());
+    let reader = ListRecordBatchReader::new(runtime, store, prefix, offset, chunk_size);
+    Ok(PyRecordBatchReader::new(Box::new(reader)))
+}
+
+/// A synchronous [`RecordBatchReader`] that lazily drains a live `ObjectStore::list`
+/// stream, `chunk_size` objects at a time, blocking the calling thread on the shared
+/// tokio runtime for each [`Iterator::next`] call.
+struct ListRecordBatchReader {
+    // Declared before `store` so it's dropped first; see `list_stream::PyListStream`
+    // for why erasing this borrow to `'static` is sound.
+    stream: BoxStream<'static, object_store::Result<ObjectMeta>>,
+    #[allow(dead_code)]
+    store: Arc<dyn ObjectStore>,
+    runtime: &'static tokio::runtime::Runtime,
+    chunk_size: usize,
+}
+
+impl ListRecordBatchReader {
+    fn new(
+        runtime: &'static tokio::runtime::Runtime,
+        store: Arc<dyn ObjectStore>,
+        prefix: Option<Path>,
+        offset: Option<Path>,
+        chunk_size: usize,
+    ) -> Self {
+        // SAFETY: see `list_stream::PyListStream::new` for the full argument.
+        let stream: BoxStream<'static, object_store::Result<ObjectMeta>> = unsafe {
+            std::mem::transmute(if let Some(offset) = &offset {
+                store.list_with_offset(prefix.as_ref(), offset)
+            } else {
+                store.list(prefix.as_ref())
+            })
+        };
+        Self {
+            stream,
+            store,
+            runtime,
+            chunk_size,
+        }
+    }
+}
+
+impl Iterator for ListRecordBatchReader {
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Self {
+            stream,
+            runtime,
+            chunk_size,
+            ..
+        } = self;
+        let chunk_size = *chunk_size;
+        runtime.block_on(async move {
+            let mut builder = ObjectMetaBuilder::with_capacity(chunk_size);
+            let mut count = 0;
+            while count < chunk_size {
+                match stream.next().await {
+                    Some(Ok(meta)) => {
+                        builder.append(meta);
+                        count += 1;
+                    }
+                    Some(Err(e)) => return Some(Err(ArrowError::ExternalError(Box::new(e)))),
+                    None => break,
+                }
+            }
+            if builder.is_empty() {
+                None
+            } else {
+                Some(Ok(builder.finish()))
+            }
+        })
+    }
+}
+
+impl RecordBatchReader for ListRecordBatchReader {
+    fn schema(&self) -> SchemaRef {
+        object_meta_schema()
+    }
+}
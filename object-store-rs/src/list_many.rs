@@ -0,0 +1,64 @@
+use std::sync::Arc;
+
+use futures::stream::FuturesUnordered;
+use futures::StreamExt;
+use indexmap::IndexMap;
+use object_store::ObjectStore;
+use pyo3::prelude::*;
+use pyo3_object_store::error::{PyObjectStoreError, PyObjectStoreResult};
+use pyo3_object_store::PyObjectStore;
+
+use crate::list::{materialize_list_stream, PyObjectMeta};
+use crate::runtime::get_runtime;
+
+/// List several prefixes concurrently and merge the results.
+///
+/// Each prefix is listed with its own call to `ObjectStore::list`, fanned out on the
+/// runtime via `FuturesUnordered` instead of the usual strictly-sequential one-prefix-
+/// at-a-time pattern, so cloud listings dominated by round-trip latency overlap rather
+/// than queue. Returns a `dict` mapping each input prefix to its listing; Python
+/// dicts preserve insertion order, and entries are inserted in whatever order the
+/// individual scans happen to finish (not the order of `prefixes`). Duplicate entries
+/// in `prefixes` are listed redundantly but collapse to one key, keeping the position
+/// of their first completion and the listing from whichever of the duplicate scans
+/// completes last.
+#[pyfunction]
+#[pyo3(signature = (store, prefixes, *, max_items_per_prefix = None))]
+pub(crate) fn list_many(
+    py: Python,
+    store: PyObjectStore,
+    prefixes: Vec<String>,
+    max_items_per_prefix: Option<usize>,
+) -> PyObjectStoreResult<IndexMap<String, Vec<PyObjectMeta>>> {
+    let store = store.into_inner();
+    let runtime = get_runtime(py)?;
+    py.allow_threads(|| {
+        let out = runtime.block_on(list_many_inner(store, prefixes, max_items_per_prefix))?;
+        Ok::<_, PyObjectStoreError>(out)
+    })
+}
+
+async fn list_many_inner(
+    store: Arc<dyn ObjectStore>,
+    prefixes: Vec<String>,
+    max_items_per_prefix: Option<usize>,
+) -> PyObjectStoreResult<IndexMap<String, Vec<PyObjectMeta>>> {
+    let mut scans = prefixes
+        .into_iter()
+        .map(|prefix| {
+            let store = store.clone();
+            async move {
+                let path = prefix.clone().into();
+                let stream = store.list(Some(&path));
+                let objects = materialize_list_stream(stream, max_items_per_prefix).await;
+                (prefix, objects)
+            }
+        })
+        .collect::<FuturesUnordered<_>>();
+
+    let mut out = IndexMap::with_capacity(scans.len());
+    while let Some((prefix, objects)) = scans.next().await {
+        out.insert(prefix, objects?);
+    }
+    Ok(out)
+}
@@ -0,0 +1,117 @@
+use std::sync::Arc;
+
+use futures::stream::BoxStream;
+use futures::StreamExt;
+use object_store::path::Path;
+use object_store::{ObjectMeta, ObjectStore};
+use pyo3::exceptions::PyStopAsyncIteration;
+use pyo3::prelude::*;
+use pyo3_object_store::error::PyObjectStoreResult;
+use tokio::sync::Mutex;
+
+use crate::list::PyObjectMeta;
+use crate::runtime::get_runtime;
+
+/// A paginated, lazily-polled stream of [`PyObjectMeta`].
+///
+/// Backed by the `BoxStream` returned from `ObjectStore::list`/`list_with_offset`, so
+/// listing proceeds at whatever pace the caller consumes it instead of being drained
+/// eagerly into a single `Vec`. Implements both the sync (`__iter__`/`__next__`) and
+/// async (`__aiter__`/`__anext__`) iterator protocols; each call polls the underlying
+/// stream for up to `chunk_size` items and returns that page as a `list`, raising
+/// `StopIteration`/`StopAsyncIteration` once the stream is exhausted.
+#[pyclass(name = "ListStream")]
+pub(crate) struct PyListStream {
+    // Declared before `store` so it's dropped first: the stream's `'static` lifetime
+    // is a lie we uphold by keeping `store` alive for exactly as long as `stream`.
+    stream: Arc<Mutex<BoxStream<'static, object_store::Result<ObjectMeta>>>>,
+    store: Arc<dyn ObjectStore>,
+    chunk_size: usize,
+}
+
+impl PyListStream {
+    pub(crate) fn new(
+        store: Arc<dyn ObjectStore>,
+        prefix: Option<Path>,
+        offset: Option<Path>,
+        chunk_size: usize,
+    ) -> Self {
+        // SAFETY: the borrow of `store` used to build the stream only lives for this
+        // expression, so erasing it to `'static` here (rather than accepting an
+        // already-borrowed `BoxStream` as an argument) lets us still move `store` into
+        // `Self` below. It stays sound because `store`'s heap allocation never moves
+        // even though the `Arc` handle does, and `stream` is dropped (via `Self`'s
+        // field order) before `store` is.
+        let stream: BoxStream<'static, object_store::Result<ObjectMeta>> = unsafe {
+            std::mem::transmute(if let Some(offset) = &offset {
+                store.list_with_offset(prefix.as_ref(), offset)
+            } else {
+                store.list(prefix.as_ref())
+            })
+        };
+        Self {
+            stream: Arc::new(Mutex::new(stream)),
+            store,
+            chunk_size,
+        }
+    }
+}
+
+async fn next_chunk(
+    stream: &Arc<Mutex<BoxStream<'static, object_store::Result<ObjectMeta>>>>,
+    chunk_size: usize,
+) -> PyObjectStoreResult<Option<Vec<PyObjectMeta>>> {
+    let mut stream = stream.lock().await;
+    let mut out = Vec::with_capacity(chunk_size);
+    while out.len() < chunk_size {
+        match stream.next().await {
+            Some(item) => out.push(PyObjectMeta::new(item?)),
+            None => break,
+        }
+    }
+    if out.is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(out))
+    }
+}
+
+#[pymethods]
+impl PyListStream {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&self, py: Python) -> PyObjectStoreResult<Option<Vec<PyObjectMeta>>> {
+        let runtime = get_runtime(py)?;
+        let stream = self.stream.clone();
+        let chunk_size = self.chunk_size;
+        py.allow_threads(|| runtime.block_on(next_chunk(&stream, chunk_size)))
+    }
+
+    fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __anext__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let stream = self.stream.clone();
+        // The `'static` on `stream`'s inner `BoxStream` is upheld only as long as
+        // `store`'s backing allocation is kept alive; clone it into the future too (not
+        // just `stream`) so a `ListStream` dropped mid-await doesn't leave the detached
+        // future polling a dangling stream.
+        let store = self.store.clone();
+        let chunk_size = self.chunk_size;
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let result = next_chunk(&stream, chunk_size).await;
+            drop(stream);
+            drop(store);
+            // Unlike the sync `__next__` slot, pyo3 doesn't map an `Ok(None)` awaitable
+            // result to `StopAsyncIteration` for us, so `async for` would otherwise see
+            // a legitimate `None` item and loop forever.
+            match result? {
+                Some(chunk) => Ok(chunk),
+                None => Err(PyStopAsyncIteration::new_err("stream exhausted")),
+            }
+        })
+    }
+}
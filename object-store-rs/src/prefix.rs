@@ -0,0 +1,121 @@
+use std::sync::Arc;
+
+use object_store::prefix::PrefixStore as ObjectStorePrefixStore;
+use object_store::ObjectStore;
+use pyo3::prelude::*;
+use pyo3_object_store::error::{PyObjectStoreError, PyObjectStoreResult};
+use pyo3_object_store::PyObjectStore;
+
+use crate::list::{
+    list_with_delimiter_materialize, materialize_list_stream, PyListResult, PyObjectMeta,
+};
+use crate::runtime::get_runtime;
+
+/// A store rooted at a fixed prefix of another store.
+///
+/// Wraps arrow-rs's own `object_store::prefix::PrefixStore`, which transparently
+/// prepends `prefix` to every path sent to the inner store and strips it back off
+/// again on the way out (`ObjectMeta.location`, `ListResult.common_prefixes`, and the
+/// offset passed to `list_with_offset` are all composed with the scope prefix exactly
+/// the way arrow-rs's own `PrefixStore` does it). This is the common multi-tenant
+/// shape: hand downstream code a `PrefixStore` rooted at `s3://bucket/dataset/` and let
+/// it address paths as if that subtree were the whole bucket.
+#[pyclass(name = "PrefixStore")]
+pub(crate) struct PyPrefixStore(Arc<dyn ObjectStore>);
+
+#[pymethods]
+impl PyPrefixStore {
+    #[new]
+    fn new(store: PyObjectStore, prefix: String) -> Self {
+        let inner = store.into_inner();
+        Self(Arc::new(ObjectStorePrefixStore::new(inner, prefix)))
+    }
+
+    fn __repr__(&self) -> String {
+        format!("PrefixStore({:?})", self.0)
+    }
+
+    #[pyo3(signature = (prefix = None, *, offset = None, max_items = 2000))]
+    fn list(
+        &self,
+        py: Python,
+        prefix: Option<String>,
+        offset: Option<String>,
+        max_items: Option<usize>,
+    ) -> PyObjectStoreResult<Vec<PyObjectMeta>> {
+        let store = self.0.clone();
+        let prefix = prefix.map(|s| s.into());
+        let runtime = get_runtime(py)?;
+        py.allow_threads(|| {
+            let stream = if let Some(offset) = offset {
+                store.list_with_offset(prefix.as_ref(), &offset.into())
+            } else {
+                store.list(prefix.as_ref())
+            };
+            let out = runtime.block_on(materialize_list_stream(stream, max_items))?;
+            Ok::<_, PyObjectStoreError>(out)
+        })
+    }
+
+    #[pyo3(signature = (prefix = None, *, offset = None, max_items = 2000))]
+    fn list_async<'py>(
+        &self,
+        py: Python<'py>,
+        prefix: Option<String>,
+        offset: Option<String>,
+        max_items: Option<usize>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let store = self.0.clone();
+        let prefix = prefix.map(|s| s.into());
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let stream = if let Some(offset) = offset {
+                store.list_with_offset(prefix.as_ref(), &offset.into())
+            } else {
+                store.list(prefix.as_ref())
+            };
+            Ok(materialize_list_stream(stream, max_items).await?)
+        })
+    }
+
+    #[pyo3(signature = (prefix = None, *, offset = None, recursive = false))]
+    fn list_with_delimiter(
+        &self,
+        py: Python,
+        prefix: Option<String>,
+        offset: Option<String>,
+        recursive: bool,
+    ) -> PyObjectStoreResult<PyListResult> {
+        let store = self.0.clone();
+        let runtime = get_runtime(py)?;
+        py.allow_threads(|| {
+            let out = runtime.block_on(list_with_delimiter_materialize(
+                store,
+                prefix.map(|s| s.into()),
+                offset,
+                recursive,
+            ))?;
+            Ok::<_, PyObjectStoreError>(out)
+        })
+    }
+
+    #[pyo3(signature = (prefix = None, *, offset = None, recursive = false))]
+    fn list_with_delimiter_async<'py>(
+        &self,
+        py: Python<'py>,
+        prefix: Option<String>,
+        offset: Option<String>,
+        recursive: bool,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let store = self.0.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let out = list_with_delimiter_materialize(
+                store,
+                prefix.map(|s| s.into()),
+                offset,
+                recursive,
+            )
+            .await?;
+            Ok(out)
+        })
+    }
+}